@@ -0,0 +1,66 @@
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// Turn `src/instructions.in` into the opcode dispatch cascade. Each spec row
+/// is an 8-bit pattern (with `x` for don't-care bits) plus the decode function
+/// that claims it; a pattern becomes a `first_byte & mask == value` arm.
+fn main() {
+    println!("cargo:rerun-if-changed=src/instructions.in");
+
+    let spec = fs::read_to_string("src/instructions.in").unwrap();
+    let mut generated = String::from("// @generated by build.rs from src/instructions.in\n\n");
+    generated.push_str(
+        "pub(crate) fn dispatch<I>(\n    \
+         first_byte: u8,\n    \
+         instruction_stream: &mut std::iter::Peekable<I>,\n\
+         ) -> Option<crate::instruction::Instruction>\n\
+         where\n    \
+         I: Iterator<Item = u8>,\n{\n",
+    );
+
+    for line in spec.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.split_whitespace();
+        let pattern = fields.next().expect("missing opcode pattern");
+        let handler = fields.next().expect("missing handler");
+        let (mask, value) = pattern_to_mask_value(pattern);
+
+        writeln!(generated, "    if first_byte & 0b{mask:08b} == 0b{value:08b} {{").unwrap();
+        writeln!(generated, "        return crate::{handler}(instruction_stream);").unwrap();
+        generated.push_str("    }\n");
+    }
+
+    generated.push_str("    unimplemented!()\n}\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("instrs.rs");
+    fs::write(out, generated).unwrap();
+}
+
+/// Split an 8-bit pattern into the mask of fixed bits and their expected value.
+fn pattern_to_mask_value(pattern: &str) -> (u8, u8) {
+    assert_eq!(pattern.len(), 8, "opcode pattern must be 8 bits: {pattern:?}");
+
+    let mut mask = 0u8;
+    let mut value = 0u8;
+    for bit in pattern.chars() {
+        mask <<= 1;
+        value <<= 1;
+        match bit {
+            '0' => mask |= 1,
+            '1' => {
+                mask |= 1;
+                value |= 1;
+            }
+            'x' => {}
+            other => panic!("invalid bit {other:?} in pattern {pattern:?}"),
+        }
+    }
+
+    (mask, value)
+}
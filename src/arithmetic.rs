@@ -0,0 +1,168 @@
+use crate::instruction::{Instruction, Operand, Size};
+use crate::mov::{
+    lookup_masked, read_immediate, register_or_memory, register_table, Direction, DIRECTIONS, SIZES,
+};
+
+/// The operation selected by bits 5-3 of the opcode (register/accumulator
+/// forms) or of the ModRM byte (the `0x80` immediate group).
+pub(crate) const OPERATIONS: [&str; 8] = ["add", "or", "adc", "sbb", "and", "sub", "xor", "cmp"];
+
+fn operation(byte: u8) -> &'static str {
+    lookup_masked(&OPERATIONS, byte, 0b0011_1000, 3)
+}
+
+pub fn disassemble_register_to_from_register<I>(
+    instruction_stream: &'_ mut I,
+) -> Option<Instruction>
+where
+    I: Iterator<Item = u8>,
+{
+    let first_byte = instruction_stream.next()?;
+    let mnemonic = operation(first_byte);
+    let direction = lookup_masked(&DIRECTIONS, first_byte, 0b0000_0010, 1);
+    let operation_size = lookup_masked(&SIZES, first_byte, 0b0000_0001, 0);
+
+    let second_byte = instruction_stream.next()?;
+    let register_table = register_table(operation_size);
+    let register = Operand::Register(lookup_masked(register_table, second_byte, 0b0011_1000, 3));
+    let register_or_memory = register_or_memory(operation_size, second_byte, instruction_stream)?;
+
+    let operands = match direction {
+        Direction::FromRegister => vec![register_or_memory, register],
+        Direction::ToRegister => vec![register, register_or_memory],
+    };
+
+    Some(Instruction { mnemonic, operands })
+}
+
+pub fn disassemble_immediate_to_accumulator<I>(
+    instruction_stream: &'_ mut I,
+) -> Option<Instruction>
+where
+    I: Iterator<Item = u8>,
+{
+    let first_byte = instruction_stream.next()?;
+    let mnemonic = operation(first_byte);
+    let operation_size = lookup_masked(&SIZES, first_byte, 0b1, 0);
+    let accumulator = Operand::Register(match operation_size {
+        Size::Byte => "al",
+        Size::Word => "ax",
+    });
+
+    let value = read_immediate(operation_size, instruction_stream)?;
+
+    Some(Instruction {
+        mnemonic,
+        operands: vec![accumulator, Operand::Immediate { value, size: None }],
+    })
+}
+
+pub fn disassemble_immediate_to_register_memory<I>(
+    instruction_stream: &'_ mut I,
+) -> Option<Instruction>
+where
+    I: Iterator<Item = u8>,
+{
+    let first_byte = instruction_stream.next()?;
+    let operation_size = lookup_masked(&SIZES, first_byte, 0b1, 0);
+    // The `s` bit widens a single byte of immediate to the operand size.
+    let sign_extend = first_byte & 0b10 != 0;
+
+    let second_byte = instruction_stream.next()?;
+    let mnemonic = operation(second_byte);
+    let register_or_memory = register_or_memory(operation_size, second_byte, instruction_stream)?;
+
+    // `s=1` (or a byte operation) carries a single sign-extended byte.
+    let value = if operation_size == Size::Word && !sign_extend {
+        read_immediate(Size::Word, instruction_stream)?
+    } else {
+        read_immediate(Size::Byte, instruction_stream)?
+    };
+
+    // A register destination carries its own size, so only tag memory.
+    let size = register_or_memory.is_memory().then_some(operation_size);
+    let immediate = Operand::Immediate { value, size };
+
+    Some(Instruction {
+        mnemonic,
+        operands: vec![register_or_memory, immediate],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_to_register() {
+        let disassembly =
+            disassemble_register_to_from_register(&mut [0b0000_0011, 0b1100_1011].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("add cx, bx".to_string()));
+    }
+
+    #[test]
+    fn memory_to_register() {
+        let disassembly =
+            disassemble_register_to_from_register(&mut [0b0011_1011, 0b0001_1000].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("cmp bx, [bx + si]".to_string()));
+    }
+
+    #[test]
+    fn immediate_to_accumulator_word() {
+        let disassembly =
+            disassemble_immediate_to_accumulator(&mut [0b0000_0101, 0b1110_1000, 0b0000_0011].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("add ax, 1000".to_string()));
+    }
+
+    #[test]
+    fn immediate_to_accumulator_byte() {
+        let disassembly =
+            disassemble_immediate_to_accumulator(&mut [0b0011_1100, 0b0000_0101].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("cmp al, 5".to_string()));
+    }
+
+    #[test]
+    fn immediate_to_memory_word() {
+        let disassembly = disassemble_immediate_to_register_memory(
+            &mut [0b1000_0001, 0b0000_0010, 0b1110_1000, 0b0000_0011].into_iter(),
+        )
+        .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("add [bp + si], word 1000".to_string()));
+    }
+
+    #[test]
+    fn immediate_to_register_sign_extended() {
+        let disassembly =
+            disassemble_immediate_to_register_memory(&mut [0b1000_0011, 0b1110_1001, 0b0000_0101].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("sub cx, 5".to_string()));
+    }
+
+    #[test]
+    fn immediate_to_register_sign_extended_negative() {
+        let disassembly =
+            disassemble_immediate_to_register_memory(&mut [0b1000_0011, 0b1110_1001, 0b1111_0100].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("sub cx, -12".to_string()));
+    }
+
+    #[test]
+    fn immediate_to_memory_byte() {
+        let disassembly =
+            disassemble_immediate_to_register_memory(&mut [0b1000_0000, 0b0011_1111, 0b0000_0111].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("cmp [bx], byte 7".to_string()));
+    }
+}
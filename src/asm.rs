@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+
+use crate::arithmetic::OPERATIONS;
+use crate::instruction::{Operand, Reg, Size};
+use crate::jump;
+use crate::mov::{BYTE_REGISTERS, MEMORY_OPERANDS, WORD_REGISTERS};
+
+/// A parsed source line: either a label definition or an instruction with its
+/// (still symbolic) arguments.
+enum Line {
+    Label(String),
+    Instruction {
+        mnemonic: String,
+        arguments: Vec<Argument>,
+    },
+}
+
+/// An instruction argument. Jump targets stay symbolic until the second pass
+/// resolves them to a displacement.
+enum Argument {
+    Operand(Operand),
+    /// A jump target: `Ok` for a named label, `Err` for a literal `$+N` offset.
+    Target(Result<String, i16>),
+}
+
+/// Assemble NASM-style `source` back into machine code.
+///
+/// The first pass walks the lines tracking each instruction's encoded length so
+/// every label's address is known; the second pass emits bytes and back-patches
+/// jump displacements against those addresses.
+pub fn assemble(source: &str) -> Option<Vec<u8>> {
+    let mut lines = Vec::new();
+    for raw in source.lines() {
+        if let Some(line) = parse_line(raw)? {
+            lines.push(line);
+        }
+    }
+
+    let mut labels = HashMap::new();
+    let mut address = 0u16;
+    for line in &lines {
+        match line {
+            Line::Label(name) => {
+                labels.insert(name.clone(), address);
+            }
+            Line::Instruction {
+                mnemonic,
+                arguments,
+            } => address += encode(mnemonic, arguments, address, &labels)?.len() as u16,
+        }
+    }
+
+    let mut machine_code = Vec::new();
+    let mut address = 0u16;
+    for line in &lines {
+        if let Line::Instruction {
+            mnemonic,
+            arguments,
+        } = line
+        {
+            let encoded = encode(mnemonic, arguments, address, &labels)?;
+            address += encoded.len() as u16;
+            machine_code.extend(encoded);
+        }
+    }
+
+    Some(machine_code)
+}
+
+fn parse_line(line: &str) -> Option<Option<Line>> {
+    let line = line.split(';').next().unwrap().trim();
+    if line.is_empty() || line == "bits 16" {
+        return Some(None);
+    }
+
+    if let Some(name) = line.strip_suffix(':') {
+        return Some(Some(Line::Label(name.trim().to_string())));
+    }
+
+    let (mnemonic, rest) = match line.split_once(char::is_whitespace) {
+        Some((mnemonic, rest)) => (mnemonic.to_string(), rest),
+        None => (line.to_string(), ""),
+    };
+
+    let arguments = if rest.trim().is_empty() {
+        Vec::new()
+    } else {
+        rest.split(',')
+            .map(|argument| parse_argument(&mnemonic, argument.trim()))
+            .collect::<Option<_>>()?
+    };
+
+    Some(Some(Line::Instruction {
+        mnemonic,
+        arguments,
+    }))
+}
+
+fn parse_argument(mnemonic: &str, text: &str) -> Option<Argument> {
+    // A conditional jump takes a single symbolic or literal target.
+    if jump::mnemonic_opcode(mnemonic).is_some() {
+        if let Some(offset) = text.strip_prefix("$+") {
+            return Some(Argument::Target(Err(offset.parse().ok()?)));
+        }
+        if let Some(offset) = text.strip_prefix("$-") {
+            return Some(Argument::Target(Err(-offset.parse::<i16>().ok()?)));
+        }
+        return Some(Argument::Target(Ok(text.to_string())));
+    }
+
+    Some(Argument::Operand(parse_operand(text)?))
+}
+
+fn parse_operand(text: &str) -> Option<Operand> {
+    if let Some((index, size)) = register(text) {
+        return Some(Operand::Register(register_name(index, size)));
+    }
+
+    if let Some(inner) = text.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        return parse_memory(inner);
+    }
+
+    // An explicit size keyword only ever precedes an immediate.
+    let (size, literal) = match text.split_once(char::is_whitespace) {
+        Some(("byte", rest)) => (Some(Size::Byte), rest.trim()),
+        Some(("word", rest)) => (Some(Size::Word), rest.trim()),
+        _ => (None, text),
+    };
+    Some(Operand::Immediate {
+        value: parse_immediate(literal)?,
+        size,
+    })
+}
+
+fn parse_memory(inner: &str) -> Option<Operand> {
+    let mut base = None;
+    let mut index = None;
+    let mut disp = 0i16;
+    let mut registers = 0;
+
+    // Rewrite subtraction as a signed addition so every term splits on `+`,
+    // keeping the sign glued to its digits (`- 37` becomes `+-37`).
+    for term in inner.replace('-', "+-").split('+') {
+        let term: String = term.chars().filter(|c| !c.is_whitespace()).collect();
+        if term.is_empty() {
+            continue;
+        }
+        if register(&term).is_some() {
+            match registers {
+                0 => base = Some(register_name_word(&term)),
+                _ => index = Some(register_name_word(&term)),
+            }
+            registers += 1;
+        } else {
+            disp = disp.wrapping_add(parse_immediate(&term)?);
+        }
+    }
+
+    if registers == 0 {
+        return Some(Operand::DirectAddress(disp as u16));
+    }
+
+    Some(Operand::Memory { base, index, disp })
+}
+
+fn parse_immediate(text: &str) -> Option<i16> {
+    text.parse::<i32>().ok().map(|value| value as i16)
+}
+
+fn register(name: &str) -> Option<(u8, Size)> {
+    if let Some(index) = WORD_REGISTERS.iter().position(|reg| *reg == name) {
+        return Some((index as u8, Size::Word));
+    }
+    BYTE_REGISTERS
+        .iter()
+        .position(|reg| *reg == name)
+        .map(|index| (index as u8, Size::Byte))
+}
+
+fn register_name(index: u8, size: Size) -> Reg {
+    match size {
+        Size::Byte => BYTE_REGISTERS[index as usize],
+        Size::Word => WORD_REGISTERS[index as usize],
+    }
+}
+
+fn register_name_word(name: &str) -> Reg {
+    WORD_REGISTERS[register(name).unwrap().0 as usize]
+}
+
+/// The `(mode, r/m, displacement bytes)` triple encoding a register or memory
+/// operand — the inverse of `mov::register_or_memory`.
+fn encode_register_or_memory(operand: &Operand) -> Option<(u8, u8, Vec<u8>)> {
+    match operand {
+        Operand::Register(name) => Some((0b11, register(name)?.0, Vec::new())),
+        Operand::DirectAddress(address) => Some((0b00, 0b110, address.to_le_bytes().to_vec())),
+        Operand::Memory { base, index, disp } => {
+            let r_m = MEMORY_OPERANDS
+                .iter()
+                .position(|pair| pair == &(*base, *index))? as u8;
+            // r/m 110 with no displacement is the direct-address escape, so a
+            // bare `[bp]` must still carry a zero 8-bit displacement.
+            if *disp == 0 && r_m != 0b110 {
+                Some((0b00, r_m, Vec::new()))
+            } else if (i8::MIN as i16..=i8::MAX as i16).contains(disp) {
+                Some((0b01, r_m, vec![*disp as u8]))
+            } else {
+                Some((0b10, r_m, disp.to_le_bytes().to_vec()))
+            }
+        }
+        _ => None,
+    }
+}
+
+fn operand_size(operands: &[&Operand]) -> Size {
+    operands
+        .iter()
+        .find_map(|operand| match operand {
+            Operand::Register(name) if WORD_REGISTERS.contains(name) => Some(Size::Word),
+            Operand::Register(_) => Some(Size::Byte),
+            Operand::Immediate { size: Some(size), .. } => Some(*size),
+            _ => None,
+        })
+        .unwrap_or(Size::Word)
+}
+
+fn encode(
+    mnemonic: &str,
+    arguments: &[Argument],
+    address: u16,
+    labels: &HashMap<String, u16>,
+) -> Option<Vec<u8>> {
+    if let Some(opcode) = jump::mnemonic_opcode(mnemonic) {
+        let Argument::Target(target) = arguments.first()? else {
+            return None;
+        };
+        // Displacements are relative to the end of the two-byte jump. During
+        // the first pass a forward label is not recorded yet; its displacement
+        // does not affect the (fixed) length, so fall back to zero until the
+        // second pass resolves it.
+        let displacement = match target {
+            Ok(label) => labels
+                .get(label)
+                .map_or(0, |target| *target as i32 - (address as i32 + 2)),
+            Err(offset) => *offset as i32 - 2,
+        };
+        return Some(vec![opcode, i8::try_from(displacement).ok()? as u8]);
+    }
+
+    let operands: Vec<&Operand> = arguments
+        .iter()
+        .map(|argument| match argument {
+            Argument::Operand(operand) => Some(operand),
+            Argument::Target(_) => None,
+        })
+        .collect::<Option<_>>()?;
+    let [destination, source] = operands.as_slice() else {
+        return None;
+    };
+    let size = operand_size(&operands);
+    let w = u8::from(size == Size::Word);
+
+    match mnemonic {
+        "mov" => encode_mov(destination, source, size, w),
+        _ => encode_arithmetic(mnemonic, destination, source, w),
+    }
+}
+
+fn encode_mov(destination: &Operand, source: &Operand, size: Size, w: u8) -> Option<Vec<u8>> {
+    match (destination, source) {
+        (Operand::Register(name), Operand::Immediate { value, size: None }) => {
+            let register = register(name)?.0;
+            let mut bytes = vec![0b1011_0000 | (w << 3) | register];
+            bytes.extend(immediate_bytes(*value, size));
+            Some(bytes)
+        }
+        (_, Operand::Immediate { value, .. }) => {
+            let (mode, r_m, displacement) = encode_register_or_memory(destination)?;
+            let mut bytes = vec![0b1100_0110 | w, modrm(mode, 0b000, r_m)];
+            bytes.extend(displacement);
+            bytes.extend(immediate_bytes(*value, size));
+            Some(bytes)
+        }
+        _ => encode_reg_rm(0b1000_1000, w, destination, source),
+    }
+}
+
+fn encode_arithmetic(
+    mnemonic: &str,
+    destination: &Operand,
+    source: &Operand,
+    w: u8,
+) -> Option<Vec<u8>> {
+    let operation = OPERATIONS.iter().position(|op| *op == mnemonic)? as u8;
+
+    if let Operand::Immediate { value, .. } = source {
+        // `al`/`ax` have a dedicated short form (`00 ooo 10 w`), so prefer it to
+        // keep the round-trip byte-exact with the disassembler's own output.
+        if let Operand::Register(name @ ("al" | "ax")) = destination {
+            let size = if *name == "ax" { Size::Word } else { Size::Byte };
+            let mut bytes = vec![(operation << 3) | 0b100 | w];
+            bytes.extend(immediate_bytes(*value, size));
+            return Some(bytes);
+        }
+    }
+
+    if let Operand::Immediate { value, size } = source {
+        let (mode, r_m, displacement) = encode_register_or_memory(destination)?;
+        let wide = size.map_or(w == 1, |size| size == Size::Word);
+        // Use the sign-extending `s` bit whenever the immediate fits a byte.
+        let sign_extend = wide && i8::try_from(*value).is_ok();
+        let first = 0b1000_0000 | (u8::from(sign_extend) << 1) | u8::from(wide);
+
+        let mut bytes = vec![first, modrm(mode, operation, r_m)];
+        bytes.extend(displacement);
+        if wide && !sign_extend {
+            bytes.extend((*value as u16).to_le_bytes());
+        } else {
+            bytes.push(*value as u8);
+        }
+        return Some(bytes);
+    }
+
+    encode_reg_rm(operation << 3, w, destination, source)
+}
+
+/// Encode a register/register or register/memory operand pair, choosing the `d`
+/// bit so the register sits in the ModRM `reg` field.
+fn encode_reg_rm(base_opcode: u8, w: u8, destination: &Operand, source: &Operand) -> Option<Vec<u8>> {
+    let (direction, register_name, register_or_memory) = match (destination, source) {
+        (Operand::Register(name), rest) => (0b10, *name, rest),
+        (rest, Operand::Register(name)) => (0b00, *name, rest),
+        _ => return None,
+    };
+
+    let (mode, r_m, displacement) = encode_register_or_memory(register_or_memory)?;
+    let reg = register(register_name)?.0;
+
+    let mut bytes = vec![base_opcode | direction | w, modrm(mode, reg, r_m)];
+    bytes.extend(displacement);
+    Some(bytes)
+}
+
+fn modrm(mode: u8, reg: u8, r_m: u8) -> u8 {
+    (mode << 6) | (reg << 3) | r_m
+}
+
+fn immediate_bytes(value: i16, size: Size) -> Vec<u8> {
+    match size {
+        Size::Byte => vec![value as u8],
+        Size::Word => (value as u16).to_le_bytes().to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_to_register() {
+        assert_eq!(assemble("bits 16\n\nmov si, bx\n"), Some(vec![0x8b, 0xf3]));
+    }
+
+    #[test]
+    fn immediate_to_register() {
+        assert_eq!(
+            assemble("mov cx, 12\n"),
+            Some(vec![0b1011_1001, 0x0c, 0x00])
+        );
+    }
+
+    #[test]
+    fn register_to_memory() {
+        assert_eq!(assemble("mov [bp + si], cl\n"), Some(vec![0x88, 0x0a]));
+    }
+
+    #[test]
+    fn two_pass_label_resolution() {
+        let source = "bits 16\n\nloop_top:\nadd ax, 1\nloop loop_top\n";
+        assert_eq!(
+            assemble(source),
+            Some(vec![0x05, 0x01, 0x00, 0xe2, 0xfb])
+        );
+    }
+
+    #[test]
+    fn forward_label_resolution() {
+        // A jump to a label defined later must resolve across the two passes.
+        let source = "bits 16\n\njne done\nadd ax, 1\ndone:\n";
+        assert_eq!(assemble(source), Some(vec![0x75, 0x03, 0x05, 0x01, 0x00]));
+    }
+
+    #[test]
+    fn negative_displacement_round_trips() {
+        // The disassembler renders negative displacements as `- n`; assembling
+        // that text back must reproduce the signed displacement byte.
+        assert_eq!(assemble("mov [bx - 32], cl\n"), Some(vec![0x88, 0x4f, 0xe0]));
+    }
+
+    #[test]
+    fn sign_extended_immediate_round_trips() {
+        // A negative immediate re-assembles to a single sign-extended byte.
+        assert_eq!(assemble("sub cx, -12\n"), Some(vec![0x83, 0xe9, 0xf4]));
+    }
+
+    #[test]
+    fn accumulator_immediate_uses_short_form() {
+        // `add ax, 1000` assembles to the accumulator short form, not the
+        // `0x80` immediate group, matching the disassembler's encoding.
+        assert_eq!(assemble("add ax, 1000\n"), Some(vec![0x05, 0xe8, 0x03]));
+    }
+
+    #[test]
+    fn arithmetic_register_to_register() {
+        assert_eq!(assemble("add cx, bx\n"), Some(vec![0x03, 0xcb]));
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::iter::Peekable;
+
+use crate::instruction::{Instruction, Operand, Size};
+use crate::mov::{BYTE_REGISTERS, WORD_REGISTERS};
+
+/// A conditional jump whose predicate the engine does not model. Execution
+/// stops with this rather than guessing whether the branch is taken, since the
+/// engine only tracks ZF and SF.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct UnmodeledPredicate(pub &'static str);
+
+impl Display for UnmodeledPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "jump predicate `{}` depends on flags this engine does not model",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnmodeledPredicate {}
+
+/// The part of a 16-bit register an operand names.
+#[derive(Clone, Copy, Debug)]
+enum RegisterAccess {
+    Word(usize),
+    Low(usize),
+    High(usize),
+}
+
+fn resolve(name: &str) -> RegisterAccess {
+    if let Some(index) = WORD_REGISTERS.iter().position(|reg| *reg == name) {
+        return RegisterAccess::Word(index);
+    }
+    let index = BYTE_REGISTERS
+        .iter()
+        .position(|reg| *reg == name)
+        .expect("unknown register");
+    // The byte table lists the four low halves first, then the four high halves.
+    if index < 4 {
+        RegisterAccess::Low(index)
+    } else {
+        RegisterAccess::High(index - 4)
+    }
+}
+
+/// The condition codes the engine tracks (ZF and SF, per the early simulation
+/// listings).
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Flags {
+    pub zero: bool,
+    pub sign: bool,
+}
+
+/// An 8086 register file, flags word and instruction pointer, plus a flat
+/// memory space the decoded stream executes against.
+pub struct Cpu {
+    registers: [u16; 8],
+    memory: Vec<u8>,
+    pub flags: Flags,
+    pub ip: u16,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Cpu {
+            registers: [0; 8],
+            memory: vec![0; 1 << 16],
+            flags: Flags::default(),
+            ip: 0,
+        }
+    }
+}
+
+impl Cpu {
+    /// The current value of a named register (e.g. `"ax"` or `"cl"`).
+    pub fn register(&self, name: &str) -> u16 {
+        self.read_register(resolve(name))
+    }
+
+    fn read_register(&self, access: RegisterAccess) -> u16 {
+        match access {
+            RegisterAccess::Word(index) => self.registers[index],
+            RegisterAccess::Low(index) => self.registers[index] & 0x00ff,
+            RegisterAccess::High(index) => (self.registers[index] >> 8) & 0x00ff,
+        }
+    }
+
+    fn write_register(&mut self, access: RegisterAccess, value: u16) {
+        match access {
+            RegisterAccess::Word(index) => self.registers[index] = value,
+            RegisterAccess::Low(index) => {
+                self.registers[index] = (self.registers[index] & 0xff00) | (value & 0x00ff);
+            }
+            RegisterAccess::High(index) => {
+                self.registers[index] = (self.registers[index] & 0x00ff) | ((value & 0x00ff) << 8);
+            }
+        }
+    }
+
+    fn effective_address(&self, base: Option<&str>, index: Option<&str>, disp: i16) -> u16 {
+        let base = base.map_or(0, |reg| self.register(reg));
+        let index = index.map_or(0, |reg| self.register(reg));
+        base.wrapping_add(index).wrapping_add(disp as u16)
+    }
+
+    fn read_operand(&self, operand: &Operand, size: Size) -> u16 {
+        match operand {
+            Operand::Register(name) => self.register(name),
+            Operand::Immediate { value, .. } => *value as u16,
+            Operand::DirectAddress(address) => self.read_memory(*address, size),
+            Operand::Memory { base, index, disp } => {
+                let address = self.effective_address(*base, *index, *disp);
+                self.read_memory(address, size)
+            }
+            Operand::JumpDisplacement(_) => 0,
+        }
+    }
+
+    fn write_operand(&mut self, operand: &Operand, size: Size, value: u16) {
+        match operand {
+            Operand::Register(name) => self.write_register(resolve(name), value),
+            Operand::DirectAddress(address) => self.write_memory(*address, size, value),
+            Operand::Memory { base, index, disp } => {
+                let address = self.effective_address(*base, *index, *disp);
+                self.write_memory(address, size, value);
+            }
+            Operand::Immediate { .. } | Operand::JumpDisplacement(_) => {}
+        }
+    }
+
+    fn read_memory(&self, address: u16, size: Size) -> u16 {
+        let lo = self.memory[address as usize] as u16;
+        match size {
+            Size::Byte => lo,
+            Size::Word => lo | ((self.memory[address.wrapping_add(1) as usize] as u16) << 8),
+        }
+    }
+
+    fn write_memory(&mut self, address: u16, size: Size, value: u16) {
+        self.memory[address as usize] = value as u8;
+        if size == Size::Word {
+            self.memory[address.wrapping_add(1) as usize] = (value >> 8) as u8;
+        }
+    }
+
+    fn set_flags(&mut self, result: u16, size: Size) {
+        let masked = match size {
+            Size::Byte => result & 0x00ff,
+            Size::Word => result,
+        };
+        self.flags.zero = masked == 0;
+        self.flags.sign = match size {
+            Size::Byte => result & 0x0080 != 0,
+            Size::Word => result & 0x8000 != 0,
+        };
+    }
+
+    fn jump_taken(&mut self, mnemonic: &'static str) -> Result<bool, UnmodeledPredicate> {
+        let taken = match mnemonic {
+            "je" => self.flags.zero,
+            "jne" => !self.flags.zero,
+            "js" => self.flags.sign,
+            "jns" => !self.flags.sign,
+            "loop" => {
+                self.decrement_cx();
+                self.registers[1] != 0
+            }
+            "loopz" => {
+                self.decrement_cx();
+                self.registers[1] != 0 && self.flags.zero
+            }
+            "loopnz" => {
+                self.decrement_cx();
+                self.registers[1] != 0 && !self.flags.zero
+            }
+            "jcxz" => self.registers[1] == 0,
+            // The remaining conditional jumps (jl/jle/jb/jbe/jp/jo and their
+            // negations) test CF/OF/PF, which this engine does not model.
+            // Surface the gap to the caller instead of guessing the branch.
+            _ => return Err(UnmodeledPredicate(mnemonic)),
+        };
+        Ok(taken)
+    }
+
+    fn decrement_cx(&mut self) {
+        self.registers[1] = self.registers[1].wrapping_sub(1);
+    }
+
+    fn step(&mut self, instruction: &Instruction) -> Result<(), UnmodeledPredicate> {
+        if let [Operand::JumpDisplacement(displacement)] = instruction.operands.as_slice() {
+            if self.jump_taken(instruction.mnemonic)? {
+                self.ip = self.ip.wrapping_add(*displacement as u16);
+            }
+            return Ok(());
+        }
+
+        let size = operand_size(&instruction.operands);
+        let destination = &instruction.operands[0];
+        let source = &instruction.operands[1];
+        let source = self.read_operand(source, size);
+
+        match instruction.mnemonic {
+            "mov" => self.write_operand(destination, size, source),
+            "add" => {
+                let result = self.read_operand(destination, size).wrapping_add(source);
+                self.set_flags(result, size);
+                self.write_operand(destination, size, result);
+            }
+            "sub" => {
+                let result = self.read_operand(destination, size).wrapping_sub(source);
+                self.set_flags(result, size);
+                self.write_operand(destination, size, result);
+            }
+            "cmp" => {
+                let result = self.read_operand(destination, size).wrapping_sub(source);
+                self.set_flags(result, size);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+fn operand_size(operands: &[Operand]) -> Size {
+    operands
+        .iter()
+        .find_map(|operand| match operand {
+            Operand::Register(name) if WORD_REGISTERS.contains(name) => Some(Size::Word),
+            Operand::Register(_) => Some(Size::Byte),
+            Operand::Immediate { size: Some(size), .. } => Some(*size),
+            _ => None,
+        })
+        .unwrap_or(Size::Word)
+}
+
+/// Decode `machine_code` into a map from each instruction's start address to
+/// the instruction and the address that follows it.
+fn decode_program(machine_code: &[u8]) -> BTreeMap<u16, (Instruction, u16)> {
+    let mut program = BTreeMap::new();
+    let mut address = 0usize;
+    while address < machine_code.len() {
+        let remaining = machine_code.len() - address;
+        let mut stream: Peekable<_> = machine_code[address..].iter().copied().peekable();
+        let Some(instruction) = crate::dissassemble_instruction(&mut stream) else {
+            break;
+        };
+        let consumed = remaining - stream.count();
+        let end = address + consumed;
+        program.insert(address as u16, (instruction, end as u16));
+        address = end;
+    }
+    program
+}
+
+/// Decode and execute `machine_code`, returning the final machine state.
+///
+/// Fails with [`UnmodeledPredicate`] if the program branches on a condition the
+/// engine does not track, rather than silently mispredicting it.
+pub fn execute(machine_code: &[u8]) -> Result<Cpu, UnmodeledPredicate> {
+    let program = decode_program(machine_code);
+    let mut cpu = Cpu::default();
+
+    while let Some((instruction, end)) = program.get(&cpu.ip) {
+        cpu.ip = *end;
+        cpu.step(instruction)?;
+    }
+
+    Ok(cpu)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_registers() {
+        let cpu = execute(&[0xb8, 0x01, 0x00, 0xbb, 0x02, 0x00, 0x01, 0xd8]).unwrap();
+
+        assert_eq!(cpu.register("ax"), 3);
+        assert!(!cpu.flags.zero);
+        assert!(!cpu.flags.sign);
+    }
+
+    #[test]
+    fn sub_to_zero_sets_zero_flag() {
+        let cpu = execute(&[0xb8, 0x05, 0x00, 0x83, 0xe8, 0x05]).unwrap();
+
+        assert_eq!(cpu.register("ax"), 0);
+        assert!(cpu.flags.zero);
+    }
+
+    #[test]
+    fn sub_byte_sets_sign_flag() {
+        let cpu = execute(&[0xb0, 0x00, 0x80, 0xe8, 0x01]).unwrap();
+
+        assert_eq!(cpu.register("al"), 0xff);
+        assert!(cpu.flags.sign);
+        assert!(!cpu.flags.zero);
+    }
+
+    #[test]
+    fn loop_accumulates() {
+        let cpu = execute(&[0xb8, 0x00, 0x00, 0xb9, 0x03, 0x00, 0x83, 0xc0, 0x01, 0xe2, 0xfb]).unwrap();
+
+        assert_eq!(cpu.register("ax"), 3);
+        assert_eq!(cpu.register("cx"), 0);
+    }
+}
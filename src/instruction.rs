@@ -0,0 +1,118 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+
+/// A register name as it appears in NASM output, e.g. `"bx"` or `"al"`.
+pub type Reg = &'static str;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Size {
+    Byte,
+    Word,
+}
+
+impl Size {
+    pub fn as_immediate_str(&self) -> &'static str {
+        match self {
+            Size::Byte => "byte",
+            Size::Word => "word",
+        }
+    }
+}
+
+impl Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_immediate_str())
+    }
+}
+
+/// A single decoded operand. The NASM text of every addressing mode falls out
+/// of the [`Display`] impl, so the decoder only has to fill in the fields.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Operand {
+    Register(Reg),
+    Memory {
+        base: Option<Reg>,
+        index: Option<Reg>,
+        disp: i16,
+    },
+    DirectAddress(u16),
+    Immediate {
+        value: i16,
+        size: Option<Size>,
+    },
+    /// A relative jump target, encoded as the signed displacement taken from
+    /// the end of the jump instruction. Rendered anchored at NASM's `$`.
+    JumpDisplacement(i8),
+}
+
+impl Operand {
+    /// Whether this operand addresses memory, and therefore needs an explicit
+    /// size keyword when paired with an immediate.
+    pub fn is_memory(&self) -> bool {
+        matches!(self, Operand::Memory { .. } | Operand::DirectAddress(_))
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(register) => f.write_str(register),
+            Operand::DirectAddress(address) => write!(f, "[{address}]"),
+            Operand::JumpDisplacement(disp) => {
+                // `$` is the start of the jump, so add back its two encoded bytes.
+                let target = *disp as i16 + 2;
+                if target < 0 {
+                    write!(f, "$-{}", target.unsigned_abs())
+                } else {
+                    write!(f, "$+{target}")
+                }
+            }
+            Operand::Immediate { value, size } => {
+                if let Some(size) = size {
+                    write!(f, "{size} {value}")
+                } else {
+                    write!(f, "{value}")
+                }
+            }
+            Operand::Memory { base, index, disp } => {
+                f.write_str("[")?;
+                let mut terms = base.iter().chain(index.iter());
+                if let Some(first) = terms.next() {
+                    f.write_str(first)?;
+                }
+                for term in terms {
+                    write!(f, " + {term}")?;
+                }
+                match disp.cmp(&0) {
+                    Ordering::Greater => write!(f, " + {disp}")?,
+                    Ordering::Less => write!(f, " - {}", disp.unsigned_abs())?,
+                    Ordering::Equal => {}
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+/// A fully decoded instruction: a mnemonic and its operands in NASM order
+/// (destination first). This is the IR the disassembler produces and the
+/// later passes consume.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Instruction {
+    pub mnemonic: &'static str,
+    pub operands: Vec<Operand>,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.mnemonic)?;
+        for (index, operand) in self.operands.iter().enumerate() {
+            if index == 0 {
+                write!(f, " {operand}")?;
+            } else {
+                write!(f, ", {operand}")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::instruction::{Instruction, Operand};
+
+/// The conditional jumps and loop instructions, each a single opcode followed
+/// by a signed 8-bit displacement.
+const JUMPS: [(u8, &str); 20] = [
+    (0b0111_0100, "je"),
+    (0b0111_1100, "jl"),
+    (0b0111_1110, "jle"),
+    (0b0111_0010, "jb"),
+    (0b0111_0110, "jbe"),
+    (0b0111_1010, "jp"),
+    (0b0111_0000, "jo"),
+    (0b0111_1000, "js"),
+    (0b0111_0101, "jne"),
+    (0b0111_1101, "jnl"),
+    (0b0111_1111, "jnle"),
+    (0b0111_0011, "jnb"),
+    (0b0111_0111, "jnbe"),
+    (0b0111_1011, "jnp"),
+    (0b0111_0001, "jno"),
+    (0b0111_1001, "jns"),
+    (0b1110_0010, "loop"),
+    (0b1110_0001, "loopz"),
+    (0b1110_0000, "loopnz"),
+    (0b1110_0011, "jcxz"),
+];
+
+/// The mnemonic for `opcode`, if it is a conditional jump or loop.
+pub fn mnemonic(opcode: u8) -> Option<&'static str> {
+    JUMPS
+        .iter()
+        .find_map(|(candidate, mnemonic)| (*candidate == opcode).then_some(*mnemonic))
+}
+
+/// The opcode for a conditional jump or loop `mnemonic`, the inverse of
+/// [`mnemonic`].
+pub fn mnemonic_opcode(mnemonic: &str) -> Option<u8> {
+    JUMPS
+        .iter()
+        .find_map(|(opcode, candidate)| (*candidate == mnemonic).then_some(*opcode))
+}
+
+pub fn disassemble_conditional_jump<I>(instruction_stream: &'_ mut I) -> Option<Instruction>
+where
+    I: Iterator<Item = u8>,
+{
+    let opcode = instruction_stream.next()?;
+    let mnemonic = mnemonic(opcode)?;
+    let displacement = instruction_stream.next()? as i8;
+
+    Some(Instruction {
+        mnemonic,
+        operands: vec![Operand::JumpDisplacement(displacement)],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backward_jump() {
+        let disassembly =
+            disassemble_conditional_jump(&mut [0b0111_0100, 0b1111_1100].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("je $-2".to_string()));
+    }
+
+    #[test]
+    fn forward_jump() {
+        let disassembly =
+            disassemble_conditional_jump(&mut [0b0111_0101, 0b0000_0010].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("jne $+4".to_string()));
+    }
+
+    #[test]
+    fn loop_to_self() {
+        let disassembly =
+            disassemble_conditional_jump(&mut [0b1110_0010, 0b1111_1110].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("loop $+0".to_string()));
+    }
+
+    #[test]
+    fn jcxz_forward() {
+        let disassembly =
+            disassemble_conditional_jump(&mut [0b1110_0011, 0b0000_0000].into_iter())
+                .map(|instruction| instruction.to_string());
+
+        assert_eq!(disassembly, Some("jcxz $+2".to_string()));
+    }
+}
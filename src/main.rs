@@ -1,17 +1,61 @@
 use std::iter::Peekable;
 
+mod arithmetic;
+mod asm;
+mod cpu;
+mod instruction;
+mod jump;
 mod mov;
 
+mod instrs {
+    include!(concat!(env!("OUT_DIR"), "/instrs.rs"));
+}
+
 const HEADER: &str = "bits 16";
 
 fn main() -> color_eyre::eyre::Result<()> {
-    let file = std::env::args().nth(1).unwrap();
-    let machine_code = std::fs::read(&file)?;
-    let dissassembly = disassemble(machine_code.into_iter().peekable());
-    print!("{dissassembly}");
+    let mut args = std::env::args().skip(1);
+    let command = args.next().unwrap();
+
+    match command.as_str() {
+        "asm" => {
+            let file = args.next().unwrap();
+            let source = std::fs::read_to_string(&file)?;
+            let machine_code = asm::assemble(&source)
+                .ok_or_else(|| color_eyre::eyre::eyre!("could not assemble {file}"))?;
+            use std::io::Write;
+            std::io::stdout().write_all(&machine_code)?;
+        }
+        "exec" => {
+            let file = args.next().unwrap();
+            let machine_code = std::fs::read(&file)?;
+            let cpu = cpu::execute(&machine_code)?;
+            print!("{}", format_state(&cpu));
+        }
+        // Anything else is the path to disassemble, preserving the original
+        // single-argument invocation.
+        file => {
+            let machine_code = std::fs::read(file)?;
+            let dissassembly = disassemble(machine_code.into_iter().peekable());
+            print!("{dissassembly}");
+        }
+    }
+
     Ok(())
 }
 
+/// Render the final register file, flags and instruction pointer of a finished
+/// simulation.
+fn format_state(cpu: &cpu::Cpu) -> String {
+    let mut state = String::new();
+    for register in mov::WORD_REGISTERS {
+        state += &format!("{register}: {:#06x}\n", cpu.register(register));
+    }
+    state += &format!("ip: {:#06x}\n", cpu.ip);
+    state += &format!("flags: {}{}\n", if cpu.flags.zero { "Z" } else { "" }, if cpu.flags.sign { "S" } else { "" });
+    state
+}
+
 fn disassemble<I>(mut machine_code: Peekable<I>) -> String
 where
     I: Iterator<Item = u8>,
@@ -19,27 +63,21 @@ where
     let mut dissassembly = format!("{HEADER}\n\n");
 
     while let Some(asm_instruction) = dissassemble_instruction(&mut machine_code) {
-        dissassembly += &asm_instruction;
+        dissassembly += &asm_instruction.to_string();
         dissassembly.push('\n');
     }
 
     dissassembly
 }
 
-fn dissassemble_instruction<I>(instruction_stream: &'_ mut Peekable<I>) -> Option<String>
+fn dissassemble_instruction<I>(
+    instruction_stream: &'_ mut Peekable<I>,
+) -> Option<instruction::Instruction>
 where
     I: Iterator<Item = u8>,
 {
     let first_byte = *instruction_stream.peek()?;
-    match first_byte & 0b1111_0000 {
-        0b1011_0000 => return mov::disassemble_immediate_to_register(instruction_stream),
-        _ => (),
-    };
-
-    match first_byte & 0b1111_1100 {
-        0b1000_1000 => return mov::disassemble_register_to_from_register(instruction_stream),
-        _ => unimplemented!(),
-    };
+    instrs::dispatch(first_byte, instruction_stream)
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
-use std::borrow::Cow;
+use crate::instruction::{Instruction, Operand, Reg, Size};
 
-pub fn disassemble_register_to_from_register<I>(instruction_stream: &'_ mut I) -> Option<String>
+pub fn disassemble_register_to_from_register<I>(
+    instruction_stream: &'_ mut I,
+) -> Option<Instruction>
 where
     I: Iterator<Item = u8>,
 {
@@ -10,44 +12,52 @@ where
 
     let second_byte = instruction_stream.next()?;
     let register_table = register_table(operation_size);
-    let register = lookup_masked(register_table, second_byte, 0b0011_1000, 3);
+    let register = Operand::Register(lookup_masked(register_table, second_byte, 0b0011_1000, 3));
     let register_or_memory = register_or_memory(operation_size, second_byte, instruction_stream)?;
 
-    let string = match direction {
-        Direction::FromRegister => format!("mov {register_or_memory}, {register}"),
-        Direction::ToRegister => format!("mov {register}, {register_or_memory}"),
+    let operands = match direction {
+        Direction::FromRegister => vec![register_or_memory, register],
+        Direction::ToRegister => vec![register, register_or_memory],
     };
 
-    Some(string)
+    Some(Instruction {
+        mnemonic: "mov",
+        operands,
+    })
 }
 
-fn register_or_memory<I>(
+pub(crate) fn register_or_memory<I>(
     size: Size,
     second_byte: u8,
     instruction_stream: &'_ mut I,
-) -> Option<Cow<'static, str>>
+) -> Option<Operand>
 where
     I: Iterator<Item = u8>,
 {
     let mode = lookup_masked(&MODES, second_byte, 0b1100_0000, 6);
-    let r_m = match mode {
-        Mode::MemoryNoDisplacement => r_m_format_no_displacement(second_byte, instruction_stream)?,
-        Mode::Memory8Bit => r_m_format_8_bit_displacement(second_byte, instruction_stream.next()?),
+    let operand = match mode {
+        Mode::MemoryNoDisplacement => r_m_no_displacement(second_byte, instruction_stream)?,
+        Mode::Memory8Bit => {
+            let displacement = instruction_stream.next()? as i8 as i16;
+            memory_operand(second_byte, displacement)
+        }
         Mode::Memory16Bit => {
             let third_byte = instruction_stream.next()?;
             let fourth_byte = instruction_stream.next()?;
-            r_m_format_16_bit_displacement(second_byte, third_byte, fourth_byte)
+            memory_operand(second_byte, i16::from_le_bytes([third_byte, fourth_byte]))
         }
         Mode::Register => {
             let register_table = register_table(size);
-            Cow::from(lookup_masked(register_table, second_byte, 0b0000_0111, 0))
+            Operand::Register(lookup_masked(register_table, second_byte, 0b0000_0111, 0))
         }
     };
 
-    Some(r_m)
+    Some(operand)
 }
 
-pub fn disassemble_immediate_to_register_memory<I>(instruction_stream: &'_ mut I) -> Option<String>
+pub fn disassemble_immediate_to_register_memory<I>(
+    instruction_stream: &'_ mut I,
+) -> Option<Instruction>
 where
     I: Iterator<Item = u8>,
 {
@@ -57,156 +67,138 @@ where
     let second_byte = instruction_stream.next()?;
     let register_or_memory = register_or_memory(operation_size, second_byte, instruction_stream)?;
 
-    let mut data = [0u8; 2];
-    data[0] = instruction_stream.next()?;
-    if operation_size == Size::Word {
-        data[1] = instruction_stream.next()?;
-    };
-    let data = u16::from_le_bytes(data);
+    let value = read_immediate(operation_size, instruction_stream)?;
 
-    let disassembly = format!(
-        "mov {register_or_memory}, {} {data}",
-        operation_size.as_immediate_str()
-    );
+    let immediate = Operand::Immediate {
+        value,
+        size: Some(operation_size),
+    };
 
-    Some(disassembly)
+    Some(Instruction {
+        mnemonic: "mov",
+        operands: vec![register_or_memory, immediate],
+    })
 }
 
-const MEMORY_STRINGS: [&str; 8] = [
-    "[bx + si]",
-    "[bx + di]",
-    "[bp + si]",
-    "[bp + di]",
-    "[si]",
-    "[di]",
-    "[bp]",
-    "[bx]",
-];
-
-fn r_m_format_no_displacement<I>(
-    second_byte: u8,
+pub fn disassemble_memory_to_from_accumulator<I>(
     instruction_stream: &'_ mut I,
-) -> Option<Cow<'static, str>>
+) -> Option<Instruction>
 where
     I: Iterator<Item = u8>,
 {
-    let second_byte = second_byte & 0b111;
-    if second_byte == 6 {
-        let address = direct_address(instruction_stream)?;
-        return Some(Cow::from(address));
-    }
-
-    Some(Cow::from(MEMORY_STRINGS[second_byte as usize]))
-}
+    let first_byte = instruction_stream.next()?;
+    let operation_size = lookup_masked(&SIZES, first_byte, 0b1, 0);
+    let accumulator = Operand::Register(match operation_size {
+        Size::Byte => "al",
+        Size::Word => "ax",
+    });
 
-fn direct_address<I>(instruction_stream: &'_ mut I) -> Option<String>
-where
-    I: Iterator<Item = u8>,
-{
     let memory_lo = instruction_stream.next()?;
     let memory_hi = instruction_stream.next()?;
-    let displacement = u16::from_le_bytes([memory_lo, memory_hi]);
-    Some(format!("[{}]", displacement.to_string()))
-}
+    let memory = Operand::DirectAddress(u16::from_le_bytes([memory_lo, memory_hi]));
 
-fn r_m_format_8_bit_displacement(second_byte: u8, third_byte: u8) -> Cow<'static, str> {
-    let second_byte = second_byte & 0b111;
-    if third_byte == 0 {
-        Cow::from(MEMORY_STRINGS[second_byte as usize])
+    // Bit 1 selects the direction: clear means memory is the source.
+    let operands = if first_byte & 0b10 == 0 {
+        vec![accumulator, memory]
     } else {
-        let displacement = u8::from_le(third_byte);
-        Cow::from(r_m_format_displacement_inner(
-            second_byte,
-            displacement as i8 as i16,
-        ))
-    }
+        vec![memory, accumulator]
+    };
+
+    Some(Instruction {
+        mnemonic: "mov",
+        operands,
+    })
 }
 
-fn r_m_format_16_bit_displacement(
-    second_byte: u8,
-    third_byte: u8,
-    fourth_byte: u8,
-) -> Cow<'static, str> {
-    let second_byte = second_byte & 0b111;
-    let displacement = i16::from_le_bytes([third_byte, fourth_byte]);
-    if displacement == 0 {
-        Cow::from(MEMORY_STRINGS[second_byte as usize])
-    } else {
-        Cow::from(r_m_format_displacement_inner(second_byte, displacement))
-    }
+/// The `(base, index)` register pair each ModRM `r/m` field selects in a memory
+/// operand, matching table 4-10 of the 8086 manual.
+pub(crate) const MEMORY_OPERANDS: [(Option<Reg>, Option<Reg>); 8] = [
+    (Some("bx"), Some("si")),
+    (Some("bx"), Some("di")),
+    (Some("bp"), Some("si")),
+    (Some("bp"), Some("di")),
+    (Some("si"), None),
+    (Some("di"), None),
+    (Some("bp"), None),
+    (Some("bx"), None),
+];
+
+fn memory_operand(r_m: u8, disp: i16) -> Operand {
+    let (base, index) = MEMORY_OPERANDS[(r_m & 0b111) as usize];
+    Operand::Memory { base, index, disp }
 }
 
-fn r_m_format_displacement_inner(second_byte: u8, displacement: i16) -> String {
-    let second_byte = second_byte & 0b111;
-    if displacement > 0 {
-        match second_byte {
-            0 => format!("[bx + si + {displacement}]"),
-            1 => format!("[bx + di + {displacement}]"),
-            2 => format!("[bp + si + {displacement}]"),
-            3 => format!("[bp + di + {displacement}]"),
-            4 => format!("[si + {displacement}]"),
-            5 => format!("[di + {displacement}]"),
-            6 => format!("[bp + {displacement}]"),
-            7 => format!("[bx + {displacement}]"),
-            _ => unreachable!(),
-        }
-    } else {
-        let displacement = -displacement;
-        match second_byte {
-            0 => format!("[bx + si - {displacement}]"),
-            1 => format!("[bx + di - {displacement}]"),
-            2 => format!("[bp + si - {displacement}]"),
-            3 => format!("[bp + di - {displacement}]"),
-            4 => format!("[si - {displacement}]"),
-            5 => format!("[di - {displacement}]"),
-            6 => format!("[bp - {displacement}]"),
-            7 => format!("[bx - {displacement}]"),
-            _ => unreachable!(),
-        }
+fn r_m_no_displacement<I>(second_byte: u8, instruction_stream: &'_ mut I) -> Option<Operand>
+where
+    I: Iterator<Item = u8>,
+{
+    if second_byte & 0b111 == 6 {
+        let memory_lo = instruction_stream.next()?;
+        let memory_hi = instruction_stream.next()?;
+        return Some(Operand::DirectAddress(u16::from_le_bytes([
+            memory_lo, memory_hi,
+        ])));
     }
+
+    Some(memory_operand(second_byte, 0))
 }
 
-pub fn disassemble_immediate_to_register<I>(instruction_stream: &'_ mut I) -> Option<String>
+pub fn disassemble_immediate_to_register<I>(instruction_stream: &'_ mut I) -> Option<Instruction>
 where
     I: Iterator<Item = u8>,
 {
     let first_byte = instruction_stream.next()?;
-
-    let mut data = [0u8; 2];
-    data[0] = instruction_stream.next()?;
-    let mut registers = BYTE_REGISTERS;
-
     let size = lookup_masked(&SIZES, first_byte, 0b0000_1000, 3);
-    if size == Size::Word {
-        data[1] = instruction_stream.next()?;
-        registers = WORD_REGISTERS;
+
+    let registers = match size {
+        Size::Byte => BYTE_REGISTERS,
+        Size::Word => WORD_REGISTERS,
     };
+    let register = Operand::Register(lookup_masked(&registers, first_byte, 0b0000_0111, 0));
+    let value = read_immediate(size, instruction_stream)?;
 
-    let register = lookup_masked(&registers, first_byte, 0b0000_0111, 0);
-    let data = u16::from_le_bytes(data);
+    Some(Instruction {
+        mnemonic: "mov",
+        operands: vec![register, Operand::Immediate { value, size: None }],
+    })
+}
 
-    Some(format!("mov {register}, {data}"))
+/// Read an immediate of the given width, sign-extending a byte so a negative
+/// value renders (and re-assembles) with its sign.
+pub(crate) fn read_immediate<I>(size: Size, instruction_stream: &'_ mut I) -> Option<i16>
+where
+    I: Iterator<Item = u8>,
+{
+    let value = match size {
+        Size::Byte => instruction_stream.next()? as i8 as i16,
+        Size::Word => {
+            let data_lo = instruction_stream.next()?;
+            let data_hi = instruction_stream.next()?;
+            i16::from_le_bytes([data_lo, data_hi])
+        }
+    };
+    Some(value)
 }
 
-const DIRECTIONS: [Direction; 2] = [Direction::FromRegister, Direction::ToRegister];
-const SIZES: [Size; 2] = [Size::Byte, Size::Word];
+pub(crate) const DIRECTIONS: [Direction; 2] = [Direction::FromRegister, Direction::ToRegister];
+pub(crate) const SIZES: [Size; 2] = [Size::Byte, Size::Word];
 const MODES: [Mode; 4] = [
     Mode::MemoryNoDisplacement,
     Mode::Memory8Bit,
     Mode::Memory16Bit,
     Mode::Register,
 ];
-const BYTE_REGISTERS: [&str; 8] = ["al", "cl", "dl", "bl", "ah", "ch", "dh", "bh"];
-const WORD_REGISTERS: [&str; 8] = ["ax", "cx", "dx", "bx", "sp", "bp", "si", "di"];
+pub(crate) const BYTE_REGISTERS: [&str; 8] = ["al", "cl", "dl", "bl", "ah", "ch", "dh", "bh"];
+pub(crate) const WORD_REGISTERS: [&str; 8] = ["ax", "cx", "dx", "bx", "sp", "bp", "si", "di"];
 
-fn register_table(operation_size: Size) -> &'static [&'static str; 8] {
+pub(crate) fn register_table(operation_size: Size) -> &'static [&'static str; 8] {
     match operation_size {
         Size::Byte => &BYTE_REGISTERS,
         Size::Word => &WORD_REGISTERS,
     }
 }
 
-fn lookup_masked<T, const N: usize>(table: &[T; N], byte: u8, mask: u8, shift: u8) -> T
+pub(crate) fn lookup_masked<T, const N: usize>(table: &[T; N], byte: u8, mask: u8, shift: u8) -> T
 where
     T: Copy,
 {
@@ -214,26 +206,11 @@ where
 }
 
 #[derive(Clone, Copy, Debug)]
-enum Direction {
+pub(crate) enum Direction {
     FromRegister,
     ToRegister,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-enum Size {
-    Byte,
-    Word,
-}
-
-impl Size {
-    fn as_immediate_str(&self) -> &'static str {
-        match self {
-            Size::Byte => "byte",
-            Size::Word => "word",
-        }
-    }
-}
-
 #[derive(Clone, Copy, Debug)]
 enum Mode {
     MemoryNoDisplacement,
@@ -249,170 +226,187 @@ mod tests {
     #[test]
     fn register_to_register_word() {
         let dissassembly =
-            disassemble_register_to_from_register(&mut [0b1000_1001, 0b1101_1110].into_iter());
-        assert_eq!(dissassembly, Some("mov si, bx".into()));
+            disassemble_register_to_from_register(&mut [0b1000_1001, 0b1101_1110].into_iter())
+                .map(|instruction| instruction.to_string());
+        assert_eq!(dissassembly, Some("mov si, bx".to_string()));
     }
 
     #[test]
     fn register_to_register_byte() {
         let disassembly =
-            disassemble_register_to_from_register(&mut [0b1000_1000, 0b1100_0110].into_iter());
-        assert_eq!(disassembly, Some("mov dh, al".into()));
+            disassemble_register_to_from_register(&mut [0b1000_1000, 0b1100_0110].into_iter())
+                .map(|instruction| instruction.to_string());
+        assert_eq!(disassembly, Some("mov dh, al".to_string()));
     }
 
     #[test]
     fn immediate_to_register_8_bit_positive() {
         let disassembly =
-            disassemble_immediate_to_register(&mut [0b1011_0001, 0b0000_1100].into_iter());
+            disassemble_immediate_to_register(&mut [0b1011_0001, 0b0000_1100].into_iter())
+                .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov cl, 12".into()));
+        assert_eq!(disassembly, Some("mov cl, 12".to_string()));
     }
 
     #[test]
     fn immediate_to_register_8_bit_negative() {
         let disassembly =
-            disassemble_immediate_to_register(&mut [0b1011_0101, 0b1111_0100].into_iter());
+            disassemble_immediate_to_register(&mut [0b1011_0101, 0b1111_0100].into_iter())
+                .map(|instruction| instruction.to_string());
 
-        // Disassembler can't distinguish sign
-        assert_eq!(disassembly, Some("mov ch, 244".into()));
+        assert_eq!(disassembly, Some("mov ch, -12".to_string()));
     }
 
     #[test]
     fn immediate_to_register_16_bit_positive_8bit() {
         let disassembly =
-            disassemble_immediate_to_register(&mut [0b1011_1001, 0b0000_1100, 0].into_iter());
+            disassemble_immediate_to_register(&mut [0b1011_1001, 0b0000_1100, 0].into_iter())
+                .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov cx, 12".into()));
+        assert_eq!(disassembly, Some("mov cx, 12".to_string()));
     }
 
     #[test]
     fn immediate_to_register_16_bit_negative_8bit() {
         let disassembly = disassemble_immediate_to_register(
             &mut [0b1011_1001, 0b1111_0100, 0b1111_1111].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        // Disassembler can't distinguish sign
-        assert_eq!(disassembly, Some("mov cx, 65524".into()));
+        assert_eq!(disassembly, Some("mov cx, -12".to_string()));
     }
 
     #[test]
     fn immediate_to_register_16_bit_positive() {
         let disassembly = disassemble_immediate_to_register(
             &mut [0b1011_1010, 0b0110_1100, 0b0000_1111].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov dx, 3948".into()));
+        assert_eq!(disassembly, Some("mov dx, 3948".to_string()));
     }
 
     #[test]
     fn immediate_to_register_16_bit_negative() {
         let disassembly = disassemble_immediate_to_register(
             &mut [0b1011_1001, 0b1001_0100, 0b1111_0000].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        // Disassembler can't distinguish sign
-        assert_eq!(disassembly, Some("mov cx, 61588".into()));
+        assert_eq!(disassembly, Some("mov cx, -3948".to_string()));
     }
 
     #[test]
     fn source_address_calculation_no_displacement_1() {
-        let disassembly = disassemble_register_to_from_register(&mut [0b1000_1010, 0].into_iter());
+        let disassembly = disassemble_register_to_from_register(&mut [0b1000_1010, 0].into_iter())
+            .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov al, [bx + si]".into()));
+        assert_eq!(disassembly, Some("mov al, [bx + si]".to_string()));
     }
 
     #[test]
     fn source_address_calculation_no_displacement_2() {
         let disassembly =
-            disassemble_register_to_from_register(&mut [0b1000_1011, 0b0001_1011].into_iter());
+            disassemble_register_to_from_register(&mut [0b1000_1011, 0b0001_1011].into_iter())
+                .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov bx, [bp + di]".into()));
+        assert_eq!(disassembly, Some("mov bx, [bp + di]".to_string()));
     }
 
     #[test]
     fn source_address_calculation_no_displacement_3() {
         let disassembly =
-            disassemble_register_to_from_register(&mut [0b1000_1011, 0b0101_0110, 0].into_iter());
+            disassemble_register_to_from_register(&mut [0b1000_1011, 0b0101_0110, 0].into_iter())
+                .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov dx, [bp]".into()));
+        assert_eq!(disassembly, Some("mov dx, [bp]".to_string()));
     }
 
     #[test]
     fn source_address_calculation_8_bit_displacement() {
         let disassembly = disassemble_register_to_from_register(
             &mut [0b1000_1010, 0b0110_0000, 0b0000_0100].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov ah, [bx + si + 4]".into()));
+        assert_eq!(disassembly, Some("mov ah, [bx + si + 4]".to_string()));
     }
 
     #[test]
     fn source_address_calculation_16_bit_displacement() {
         let disassembly = disassemble_register_to_from_register(
             &mut [0b1000_1010, 0b1000_0000, 0b1000_0111, 0b0001_0011].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov al, [bx + si + 4999]".into()));
+        assert_eq!(disassembly, Some("mov al, [bx + si + 4999]".to_string()));
     }
 
     #[test]
     fn destination_address_calculation_no_displacement_1() {
         let disassembly =
-            disassemble_register_to_from_register(&mut [0b1000_1001, 0b0000_1001].into_iter());
+            disassemble_register_to_from_register(&mut [0b1000_1001, 0b0000_1001].into_iter())
+                .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov [bx + di], cx".into()));
+        assert_eq!(disassembly, Some("mov [bx + di], cx".to_string()));
     }
 
     #[test]
     fn destination_address_calculation_no_displacement_2() {
         let disassembly =
-            disassemble_register_to_from_register(&mut [0b1000_1000, 0b0000_1010].into_iter());
+            disassemble_register_to_from_register(&mut [0b1000_1000, 0b0000_1010].into_iter())
+                .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov [bp + si], cl".into()));
+        assert_eq!(disassembly, Some("mov [bp + si], cl".to_string()));
     }
 
     #[test]
     fn destination_address_calculation_no_displacement_3() {
         let disassembly =
-            disassemble_register_to_from_register(&mut [0b1000_1000, 0b0110_1110, 0].into_iter());
+            disassemble_register_to_from_register(&mut [0b1000_1000, 0b0110_1110, 0].into_iter())
+                .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov [bp], ch".into()));
+        assert_eq!(disassembly, Some("mov [bp], ch".to_string()));
     }
 
     #[test]
     fn signed_displacement_1() {
         let disassembly = disassemble_register_to_from_register(
             &mut [0b1000_1011, 0b0100_0001, 0b1101_1011].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov ax, [bx + di - 37]".into()));
+        assert_eq!(disassembly, Some("mov ax, [bx + di - 37]".to_string()));
     }
 
     #[test]
     fn signed_displacement_2() {
         let disassembly = disassemble_register_to_from_register(
             &mut [0b1000_1001, 0b1000_1100, 0b1101_0100, 0b1111_1110].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov [si - 300], cx".into()));
+        assert_eq!(disassembly, Some("mov [si - 300], cx".to_string()));
     }
 
     #[test]
     fn signed_displacement_3() {
         let disassembly = disassemble_register_to_from_register(
             &mut [0b1000_1011, 0b0101_0111, 0b1110_0000].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov dx, [bx - 32]".into()));
+        assert_eq!(disassembly, Some("mov dx, [bx - 32]".to_string()));
     }
 
     #[test]
     fn explicit_size_byte() {
         let disassembly = disassemble_immediate_to_register_memory(
             &mut [0b1100_0110, 0b0000_0011, 0b0000_0111].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov [bp + di], byte 7".into()));
+        assert_eq!(disassembly, Some("mov [bp + di], byte 7".to_string()));
     }
 
     #[test]
@@ -427,60 +421,67 @@ mod tests {
                 0b0000_0001,
             ]
             .into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov [di + 901], word 347".into()));
+        assert_eq!(disassembly, Some("mov [di + 901], word 347".to_string()));
     }
 
     #[test]
     fn direct_address_byte() {
         let disassembly = disassemble_register_to_from_register(
             &mut [0b1000_1011, 0b0010_1110, 0b0000_0101, 0].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov bp, [5]".into()));
+        assert_eq!(disassembly, Some("mov bp, [5]".to_string()));
     }
 
     #[test]
     fn direct_address_word() {
         let disassembly = disassemble_register_to_from_register(
             &mut [0b1000_1011, 0b0001_1110, 0b1000_0010, 0b0000_1101].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov bx, [3458]".into()));
+        assert_eq!(disassembly, Some("mov bx, [3458]".to_string()));
     }
 
     #[test]
     fn memory_to_accumulator_word_16_bit() {
         let disassembly = disassemble_memory_to_from_accumulator(
             &mut [0b1010_0001, 0b1111_1011, 0b0000_1001].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov ax, [2555]".into()));
+        assert_eq!(disassembly, Some("mov ax, [2555]".to_string()));
     }
 
     #[test]
     fn memory_to_accumulator_word_8_bit() {
         let disassembly =
-            disassemble_memory_to_from_accumulator(&mut [0b1010_0001, 0b0001_0000, 0].into_iter());
+            disassemble_memory_to_from_accumulator(&mut [0b1010_0001, 0b0001_0000, 0].into_iter())
+                .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov ax, [16]".into()));
+        assert_eq!(disassembly, Some("mov ax, [16]".to_string()));
     }
 
     #[test]
     fn accumulator_to_memory_word_16_bit() {
         let disassembly = disassemble_memory_to_from_accumulator(
             &mut [0b1010_0011, 0b1111_1010, 0b0000_1001].into_iter(),
-        );
+        )
+        .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov [2554], ax".into()));
+        assert_eq!(disassembly, Some("mov [2554], ax".to_string()));
     }
 
     #[test]
     fn accumulator_to_memory_word_8_bit() {
         let disassembly =
-            disassemble_memory_to_from_accumulator(&mut [0b1010_0011, 0b0000_1111, 0].into_iter());
+            disassemble_memory_to_from_accumulator(&mut [0b1010_0011, 0b0000_1111, 0].into_iter())
+                .map(|instruction| instruction.to_string());
 
-        assert_eq!(disassembly, Some("mov [15], ax".into()));
+        assert_eq!(disassembly, Some("mov [15], ax".to_string()));
     }
 }